@@ -1,5 +1,6 @@
 use std::fmt;
-use std::ops::Range;
+use std::iter::FusedIterator;
+use std::ops::{Bound, Range, RangeBounds};
 use std::sync::Arc;
 
 use arrayvec::ArrayVec;
@@ -10,52 +11,177 @@ pub trait ArcSliceSplit: Sized {
 
     fn arc_slice_split_first(&self) -> Option<(&Self::Item, Self)>;
     fn arc_slice_split_last(&self) -> Option<(&Self::Item, Self)>;
+    fn arc_slice_remaining_len(&self) -> usize;
+}
+
+fn translate_range<R: RangeBounds<usize>>(range: R, len: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        None
+    } else {
+        Some(start..end)
+    }
 }
 
-pub struct ArcSlice<T> {
+pub struct ArcSlice<T: 'static> {
     inner: ArcSliceInner<T>,
 }
 
-impl<T> ArcSlice<T> {
+impl<T: 'static> ArcSlice<T> {
+    pub const fn from_static(slice: &'static [T]) -> Self {
+        let range = 0..slice.len();
+        Self { inner: ArcSliceInner::Static(slice, range) }
+    }
+
+    pub fn from_arc(arc: Arc<[T]>) -> Self {
+        let range = 0..arc.len();
+        Self { inner: ArcSliceInner::Shared(arc, range) }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        self.get(range).expect("range out of bounds")
+    }
+
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let sub = translate_range(range, self.raw_inner_slice().len())?;
+        Some(match &self.inner {
+            ArcSliceInner::Empty => Self { inner: ArcSliceInner::Empty },
+            ArcSliceInner::Shared(slice, cur) => Self {
+                inner: ArcSliceInner::Shared(slice.clone(), (cur.start + sub.start)..(cur.start + sub.end)),
+            },
+            ArcSliceInner::Static(slice, cur) => Self {
+                inner: ArcSliceInner::Static(slice, (cur.start + sub.start)..(cur.start + sub.end)),
+            },
+        })
+    }
+
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        self.checked_split_at(mid).expect("mid out of bounds")
+    }
+
+    pub fn checked_split_at(&self, mid: usize) -> Option<(Self, Self)> {
+        if mid > self.raw_inner_slice().len() {
+            None
+        } else {
+            Some((self.slice(..mid), self.slice(mid..)))
+        }
+    }
+
+    pub fn make_mut(&mut self) -> &mut [T]
+    where
+        T: Clone,
+    {
+        let uniquely_owned = matches!(
+            &self.inner,
+            ArcSliceInner::Shared(arc, range)
+                if range.start == 0
+                    && range.end == arc.len()
+                    && Arc::strong_count(arc) == 1
+                    && Arc::weak_count(arc) == 0
+        );
+        if uniquely_owned {
+            return match &mut self.inner {
+                ArcSliceInner::Shared(arc, _) => Arc::get_mut(arc).expect("checked uniquely owned above"),
+                ArcSliceInner::Empty | ArcSliceInner::Static(..) => unreachable!(),
+            };
+        }
+        let values: Vec<T> = self.raw_inner_slice().to_vec();
+        let len = values.len();
+        self.inner = ArcSliceInner::Shared(Arc::from(values), 0..len);
+        match &mut self.inner {
+            ArcSliceInner::Shared(arc, _) => Arc::get_mut(arc).expect("freshly allocated Arc is uniquely owned"),
+            ArcSliceInner::Empty | ArcSliceInner::Static(..) => unreachable!(),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        match self.inner {
+            ArcSliceInner::Empty => Vec::new(),
+            // `Arc<[T]>` is unsized, so `Arc::try_unwrap` (which requires `T: Sized`) can never
+            // reclaim its allocation; cloning the logical range is the only option here.
+            ArcSliceInner::Shared(arc, range) => arc[range].to_vec(),
+            ArcSliceInner::Static(slice, range) => slice[range].to_vec(),
+        }
+    }
+
     fn raw_inner_slice(&self) -> &[T] {
         match &self.inner {
             ArcSliceInner::Empty => &[],
             ArcSliceInner::Shared(slice, range) => &slice[range.clone()],
+            ArcSliceInner::Static(slice, range) => &slice[range.clone()],
         }
     }
 }
 
-impl<T> ArcSliceSplit for ArcSlice<T> {
+impl<T: 'static> ArcSliceSplit for ArcSlice<T> {
     type Item = T;
 
     fn arc_slice_split_first(&self) -> Option<(&Self::Item, Self)> {
-        let ArcSliceInner::Shared(slice, range) = &self.inner else {
-            return None;
-        };
-        if range.start < range.end {
-            Some((&slice[range.clone()][0], Self {
-                inner: ArcSliceInner::Shared(slice.clone(), (range.start + 1)..range.end),
-            }))
-        } else {
-            None
+        match &self.inner {
+            ArcSliceInner::Empty => None,
+            ArcSliceInner::Shared(slice, range) => {
+                if range.start < range.end {
+                    Some((&slice[range.clone()][0], Self {
+                        inner: ArcSliceInner::Shared(slice.clone(), (range.start + 1)..range.end),
+                    }))
+                } else {
+                    None
+                }
+            }
+            ArcSliceInner::Static(slice, range) => {
+                if range.start < range.end {
+                    Some((&slice[range.clone()][0], Self {
+                        inner: ArcSliceInner::Static(slice, (range.start + 1)..range.end),
+                    }))
+                } else {
+                    None
+                }
+            }
         }
     }
 
     fn arc_slice_split_last(&self) -> Option<(&Self::Item, Self)> {
-        let ArcSliceInner::Shared(slice, range) = &self.inner else {
-            return None;
-        };
-        if range.start < range.end {
-            Some((slice[range.clone()].last().unwrap(), Self {
-                inner: ArcSliceInner::Shared(slice.clone(), range.start..(range.end - 1)),
-            }))
-        } else {
-            None
+        match &self.inner {
+            ArcSliceInner::Empty => None,
+            ArcSliceInner::Shared(slice, range) => {
+                if range.start < range.end {
+                    Some((slice[range.clone()].last().unwrap(), Self {
+                        inner: ArcSliceInner::Shared(slice.clone(), range.start..(range.end - 1)),
+                    }))
+                } else {
+                    None
+                }
+            }
+            ArcSliceInner::Static(slice, range) => {
+                if range.start < range.end {
+                    Some((slice[range.clone()].last().unwrap(), Self {
+                        inner: ArcSliceInner::Static(slice, range.start..(range.end - 1)),
+                    }))
+                } else {
+                    None
+                }
+            }
         }
     }
+
+    fn arc_slice_remaining_len(&self) -> usize {
+        self.raw_inner_slice().len()
+    }
 }
 
-impl<T, const N: usize> From<[T; N]> for ArcSlice<T> {
+impl<T: 'static, const N: usize> From<[T; N]> for ArcSlice<T> {
     fn from(values: [T; N]) -> Self {
         if N == 0 {
             Self { inner: ArcSliceInner::Empty }
@@ -67,7 +193,13 @@ impl<T, const N: usize> From<[T; N]> for ArcSlice<T> {
     }
 }
 
-impl<T> FromIterator<T> for ArcSlice<T> {
+impl<T: 'static> From<Arc<[T]>> for ArcSlice<T> {
+    fn from(arc: Arc<[T]>) -> Self {
+        Self::from_arc(arc)
+    }
+}
+
+impl<T: 'static> FromIterator<T> for ArcSlice<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut iter = iter.into_iter();
         if let Some(first) = iter.next() {
@@ -80,7 +212,7 @@ impl<T> FromIterator<T> for ArcSlice<T> {
     }
 }
 
-impl<T> Default for ArcSlice<T> {
+impl<T: 'static> Default for ArcSlice<T> {
     fn default() -> Self {
         Self {
             inner: ArcSliceInner::Empty,
@@ -88,33 +220,33 @@ impl<T> Default for ArcSlice<T> {
     }
 }
 
-impl<T: std::hash::Hash> std::hash::Hash for ArcSlice<T> {
+impl<T: std::hash::Hash + 'static> std::hash::Hash for ArcSlice<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.raw_inner_slice().hash(state)
     }
 }
 
-impl<T: Ord> Ord for ArcSlice<T> {
+impl<T: Ord + 'static> Ord for ArcSlice<T> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.raw_inner_slice().cmp(other.raw_inner_slice())
     }
 }
 
-impl<T: PartialOrd> PartialOrd for ArcSlice<T> {
+impl<T: PartialOrd + 'static> PartialOrd for ArcSlice<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.raw_inner_slice().partial_cmp(other.raw_inner_slice())
     }
 }
 
-impl<T: Eq> Eq for ArcSlice<T> {}
+impl<T: Eq + 'static> Eq for ArcSlice<T> {}
 
-impl<T: PartialEq> PartialEq for ArcSlice<T> {
+impl<T: PartialEq + 'static> PartialEq for ArcSlice<T> {
     fn eq(&self, other: &Self) -> bool {
         self.raw_inner_slice() == other.raw_inner_slice()
     }
 }
 
-impl<T> Clone for ArcSlice<T> {
+impl<T: 'static> Clone for ArcSlice<T> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -122,7 +254,7 @@ impl<T> Clone for ArcSlice<T> {
     }
 }
 
-impl<T> std::ops::Deref for ArcSlice<T> {
+impl<T: 'static> std::ops::Deref for ArcSlice<T> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -130,13 +262,13 @@ impl<T> std::ops::Deref for ArcSlice<T> {
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for ArcSlice<T> {
+impl<T: fmt::Debug + 'static> fmt::Debug for ArcSlice<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.raw_inner_slice().fmt(f)
     }
 }
 
-impl<T> IntoIterator for ArcSlice<T>
+impl<T: 'static> IntoIterator for ArcSlice<T>
 where
     T: Clone,
 {
@@ -148,36 +280,142 @@ where
     }
 }
 
-enum ArcSliceInner<T> {
+enum ArcSliceInner<T: 'static> {
     Empty,
     Shared(Arc<[T]>, Range<usize>),
+    Static(&'static [T], Range<usize>),
 }
 
-impl<T> Clone for ArcSliceInner<T> {
+impl<T: 'static> Clone for ArcSliceInner<T> {
     fn clone(&self) -> Self {
         match self {
             Self::Empty => Self::Empty,
             Self::Shared(arc, range) => Self::Shared(arc.clone(), range.clone()),
+            Self::Static(slice, range) => Self::Static(slice, range.clone()),
         }
     }
 }
 
 #[derive(Clone)]
-pub struct SmallArcSlice<T, const CAP: usize> {
+pub struct SmallArcSlice<T: 'static, const CAP: usize> {
     inner: SmallArcSliceInner<T, CAP>,
     range: Range<usize>,
 }
 
-impl<T, const CAP: usize> SmallArcSlice<T, CAP> {
+impl<T: 'static, const CAP: usize> SmallArcSlice<T, CAP> {
+    pub const fn from_static(slice: &'static [T]) -> Self {
+        let range = 0..slice.len();
+        Self { inner: SmallArcSliceInner::Static(slice), range }
+    }
+
+    pub fn from_arc(arc: Arc<[T]>) -> Self {
+        let range = 0..arc.len();
+        Self { inner: SmallArcSliceInner::Shared(arc), range }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self
+    where
+        T: Clone,
+    {
+        self.get(range).expect("range out of bounds")
+    }
+
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let sub = translate_range(range, self.range.len())?;
+        Some(Self {
+            inner: self.inner.clone(),
+            range: (self.range.start + sub.start)..(self.range.start + sub.end),
+        })
+    }
+
+    pub fn split_at(&self, mid: usize) -> (Self, Self)
+    where
+        T: Clone,
+    {
+        self.checked_split_at(mid).expect("mid out of bounds")
+    }
+
+    pub fn checked_split_at(&self, mid: usize) -> Option<(Self, Self)>
+    where
+        T: Clone,
+    {
+        if mid > self.range.len() {
+            None
+        } else {
+            Some((self.slice(..mid), self.slice(mid..)))
+        }
+    }
+
+    pub fn make_mut(&mut self) -> &mut [T]
+    where
+        T: Clone,
+    {
+        let inline_full = matches!(
+            &self.inner,
+            SmallArcSliceInner::Inline(array) if self.range.start == 0 && self.range.end == array.len()
+        );
+        if inline_full {
+            return match &mut self.inner {
+                SmallArcSliceInner::Inline(array) => &mut array[..],
+                _ => unreachable!(),
+            };
+        }
+        let shared_unique = matches!(
+            &self.inner,
+            SmallArcSliceInner::Shared(arc)
+                if self.range.start == 0
+                    && self.range.end == arc.len()
+                    && Arc::strong_count(arc) == 1
+                    && Arc::weak_count(arc) == 0
+        );
+        if shared_unique {
+            return match &mut self.inner {
+                SmallArcSliceInner::Shared(arc) => Arc::get_mut(arc).expect("checked uniquely owned above"),
+                _ => unreachable!(),
+            };
+        }
+        let values: Vec<T> = self.raw_inner_slice().to_vec();
+        let len = values.len();
+        self.inner = if len <= CAP {
+            SmallArcSliceInner::Inline(values.into_iter().collect())
+        } else {
+            SmallArcSliceInner::Shared(Arc::from(values))
+        };
+        self.range = 0..len;
+        match &mut self.inner {
+            SmallArcSliceInner::Inline(array) => &mut array[..],
+            SmallArcSliceInner::Shared(arc) => Arc::get_mut(arc).expect("freshly allocated Arc is uniquely owned"),
+            SmallArcSliceInner::Static(_) => unreachable!(),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let range = self.range;
+        match self.inner {
+            SmallArcSliceInner::Inline(mut array) => array.drain(range).collect(),
+            // `Arc<[T]>` is unsized, so `Arc::try_unwrap` can never reclaim its allocation;
+            // cloning the logical range is the only option for the `Shared` case.
+            SmallArcSliceInner::Shared(arc) => arc[range].to_vec(),
+            SmallArcSliceInner::Static(slice) => slice[range].to_vec(),
+        }
+    }
+
     fn raw_inner_slice(&self) -> &[T] {
         match &self.inner {
             SmallArcSliceInner::Inline(slice) => &slice[self.range.clone()],
             SmallArcSliceInner::Shared(slice) => &slice[self.range.clone()],
+            SmallArcSliceInner::Static(slice) => &slice[self.range.clone()],
         }
     }
 }
 
-impl<T, const CAP: usize> ArcSliceSplit for SmallArcSlice<T, CAP>
+impl<T: 'static, const CAP: usize> ArcSliceSplit for SmallArcSlice<T, CAP>
 where
     T: Clone,
 {
@@ -204,9 +442,13 @@ where
             None
         }
     }
+
+    fn arc_slice_remaining_len(&self) -> usize {
+        self.range.len()
+    }
 }
 
-impl<T, const CAP: usize, const N: usize> From<[T; N]> for SmallArcSlice<T, CAP> {
+impl<T: 'static, const CAP: usize, const N: usize> From<[T; N]> for SmallArcSlice<T, CAP> {
     fn from(values: [T; N]) -> Self {
         let range = 0..N;
         if N <= CAP {
@@ -223,7 +465,13 @@ impl<T, const CAP: usize, const N: usize> From<[T; N]> for SmallArcSlice<T, CAP>
     }
 }
 
-impl<T, const CAP: usize> FromIterator<T> for SmallArcSlice<T, CAP> {
+impl<T: 'static, const CAP: usize> From<Arc<[T]>> for SmallArcSlice<T, CAP> {
+    fn from(arc: Arc<[T]>) -> Self {
+        Self::from_arc(arc)
+    }
+}
+
+impl<T: 'static, const CAP: usize> FromIterator<T> for SmallArcSlice<T, CAP> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut array = ArrayVec::new();
         let mut iter = iter.into_iter();
@@ -246,7 +494,7 @@ impl<T, const CAP: usize> FromIterator<T> for SmallArcSlice<T, CAP> {
     }
 }
 
-impl<T, const CAP: usize> Default for SmallArcSlice<T, CAP> {
+impl<T: 'static, const CAP: usize> Default for SmallArcSlice<T, CAP> {
     fn default() -> Self {
         Self {
             inner: SmallArcSliceInner::Inline(ArrayVec::new()),
@@ -255,35 +503,35 @@ impl<T, const CAP: usize> Default for SmallArcSlice<T, CAP> {
     }
 }
 
-impl<T: std::hash::Hash, const CAP: usize> std::hash::Hash for SmallArcSlice<T, CAP> {
+impl<T: std::hash::Hash + 'static, const CAP: usize> std::hash::Hash for SmallArcSlice<T, CAP> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.raw_inner_slice().hash(state);
     }
 }
 
-impl<T: Ord, const CAP: usize> Ord for SmallArcSlice<T, CAP> {
+impl<T: Ord + 'static, const CAP: usize> Ord for SmallArcSlice<T, CAP> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.raw_inner_slice().cmp(other.raw_inner_slice())
     }
 }
 
-impl<T: PartialOrd, const CAP1: usize, const CAP2: usize> PartialOrd<SmallArcSlice<T, CAP2>>
+impl<T: PartialOrd + 'static, const CAP1: usize, const CAP2: usize> PartialOrd<SmallArcSlice<T, CAP2>>
 for SmallArcSlice<T, CAP1> {
     fn partial_cmp(&self, other: &SmallArcSlice<T, CAP2>) -> Option<std::cmp::Ordering> {
         self.raw_inner_slice().partial_cmp(other.raw_inner_slice())
     }
 }
 
-impl<T: Eq, const CAP: usize> Eq for SmallArcSlice<T, CAP> {}
+impl<T: Eq + 'static, const CAP: usize> Eq for SmallArcSlice<T, CAP> {}
 
-impl<T: PartialEq, const CAP1: usize, const CAP2: usize> PartialEq<SmallArcSlice<T, CAP2>>
+impl<T: PartialEq + 'static, const CAP1: usize, const CAP2: usize> PartialEq<SmallArcSlice<T, CAP2>>
 for SmallArcSlice<T, CAP1> {
     fn eq(&self, other: &SmallArcSlice<T, CAP2>) -> bool {
         self.raw_inner_slice() == other.raw_inner_slice()
     }
 }
 
-impl<T, const CAP: usize> std::ops::Deref for SmallArcSlice<T, CAP> {
+impl<T: 'static, const CAP: usize> std::ops::Deref for SmallArcSlice<T, CAP> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -291,13 +539,13 @@ impl<T, const CAP: usize> std::ops::Deref for SmallArcSlice<T, CAP> {
     }
 }
 
-impl<T: fmt::Debug, const CAP: usize> fmt::Debug for SmallArcSlice<T, CAP> {
+impl<T: fmt::Debug + 'static, const CAP: usize> fmt::Debug for SmallArcSlice<T, CAP> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.raw_inner_slice().fmt(f)
     }
 }
 
-impl<T, const CAP: usize> IntoIterator for SmallArcSlice<T, CAP>
+impl<T: 'static, const CAP: usize> IntoIterator for SmallArcSlice<T, CAP>
 where
     T: Clone,
 {
@@ -310,9 +558,10 @@ where
 }
 
 #[derive(Clone)]
-enum SmallArcSliceInner<T, const CAP: usize> {
+enum SmallArcSliceInner<T: 'static, const CAP: usize> {
     Inline(ArrayVec<T, CAP>),
     Shared(Arc<[T]>),
+    Static(&'static [T]),
 }
 
 #[derive(Clone)]
@@ -336,4 +585,419 @@ where
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.arc_slice_remaining_len();
+        (len, Some(len))
+    }
+}
+
+impl<I> DoubleEndedIterator for ArcSliceIter<I>
+where
+    I: ArcSliceSplit,
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((next, rest)) = self.slice.arc_slice_split_last() {
+            let next = next.clone();
+            self.slice = rest;
+            Some(next)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for ArcSliceIter<I>
+where
+    I: ArcSliceSplit,
+    I::Item: Clone,
+{
+    fn len(&self) -> usize {
+        self.slice.arc_slice_remaining_len()
+    }
+}
+
+impl<I> FusedIterator for ArcSliceIter<I>
+where
+    I: ArcSliceSplit,
+    I::Item: Clone,
+{
+}
+
+#[derive(Clone)]
+pub struct ArcStr {
+    inner: ArcSlice<u8>,
+}
+
+impl ArcStr {
+    pub const fn from_static(s: &'static str) -> Self {
+        Self { inner: ArcSlice::from_static(s.as_bytes()) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(&self.inner) }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        self.get(range).expect("range out of bounds or not on a char boundary")
+    }
+
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let sub = translate_range(range, self.inner.len())?;
+        let s = self.as_str();
+        if !s.is_char_boundary(sub.start) || !s.is_char_boundary(sub.end) {
+            return None;
+        }
+        Some(Self { inner: self.inner.slice(sub) })
+    }
+}
+
+impl TryFrom<ArcSlice<u8>> for ArcStr {
+    type Error = std::str::Utf8Error;
+
+    fn try_from(inner: ArcSlice<u8>) -> Result<Self, Self::Error> {
+        std::str::from_utf8(&inner)?;
+        Ok(Self { inner })
+    }
+}
+
+impl std::ops::Deref for ArcStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for ArcStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl std::hash::Hash for ArcStr {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Ord for ArcStr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialOrd for ArcStr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for ArcStr {}
+
+impl PartialEq for ArcStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[derive(Clone)]
+pub struct SmallArcStr<const CAP: usize> {
+    inner: SmallArcSlice<u8, CAP>,
+}
+
+impl<const CAP: usize> SmallArcStr<CAP> {
+    pub const fn from_static(s: &'static str) -> Self {
+        Self { inner: SmallArcSlice::from_static(s.as_bytes()) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(&self.inner) }
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        self.get(range).expect("range out of bounds or not on a char boundary")
+    }
+
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let sub = translate_range(range, self.inner.len())?;
+        let s = self.as_str();
+        if !s.is_char_boundary(sub.start) || !s.is_char_boundary(sub.end) {
+            return None;
+        }
+        Some(Self { inner: self.inner.slice(sub) })
+    }
+}
+
+impl<const CAP: usize> TryFrom<SmallArcSlice<u8, CAP>> for SmallArcStr<CAP> {
+    type Error = std::str::Utf8Error;
+
+    fn try_from(inner: SmallArcSlice<u8, CAP>) -> Result<Self, Self::Error> {
+        std::str::from_utf8(&inner)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<const CAP: usize> std::ops::Deref for SmallArcStr<CAP> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for SmallArcStr<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const CAP: usize> std::hash::Hash for SmallArcStr<CAP> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const CAP: usize> Ord for SmallArcStr<CAP> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const CAP1: usize, const CAP2: usize> PartialOrd<SmallArcStr<CAP2>> for SmallArcStr<CAP1> {
+    fn partial_cmp(&self, other: &SmallArcStr<CAP2>) -> Option<std::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<const CAP: usize> Eq for SmallArcStr<CAP> {}
+
+impl<const CAP1: usize, const CAP2: usize> PartialEq<SmallArcStr<CAP2>> for SmallArcStr<CAP1> {
+    fn eq(&self, other: &SmallArcStr<CAP2>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_str_try_from_round_trips_valid_utf8() {
+        let slice: ArcSlice<u8> = ArcSlice::from(*b"h\xc3\xa9llo");
+        let s = ArcStr::try_from(slice).unwrap();
+        assert_eq!(&*s, "héllo");
+    }
+
+    #[test]
+    fn arc_str_try_from_rejects_invalid_utf8() {
+        let slice: ArcSlice<u8> = ArcSlice::from([0xff, 0xfe]);
+        assert!(ArcStr::try_from(slice).is_err());
+    }
+
+    #[test]
+    fn arc_str_get_rejects_mid_codepoint_boundary() {
+        let s = ArcStr::from_static("héllo");
+        // 'é' is a 2-byte codepoint starting at byte 1, so byte 2 is mid-codepoint.
+        assert!(s.get(0..2).is_none());
+        assert!(s.get(2..).is_none());
+    }
+
+    #[test]
+    fn arc_str_get_accepts_boundary_at_len() {
+        let s = ArcStr::from_static("héllo");
+        assert_eq!(&*s.get(0..s.len()).unwrap(), "héllo");
+    }
+
+    #[test]
+    fn small_arc_str_try_from_round_trips_valid_utf8() {
+        let slice: SmallArcSlice<u8, 16> = SmallArcSlice::from(*b"h\xc3\xa9llo");
+        let s = SmallArcStr::try_from(slice).unwrap();
+        assert_eq!(&*s, "héllo");
+    }
+
+    #[test]
+    fn small_arc_str_try_from_rejects_invalid_utf8() {
+        let slice: SmallArcSlice<u8, 16> = SmallArcSlice::from([0xff, 0xfe]);
+        assert!(SmallArcStr::try_from(slice).is_err());
+    }
+
+    #[test]
+    fn small_arc_str_get_rejects_mid_codepoint_boundary() {
+        let s = SmallArcStr::<16>::from_static("héllo");
+        assert!(s.get(0..2).is_none());
+        assert!(s.get(2..).is_none());
+    }
+
+    #[test]
+    fn small_arc_str_get_accepts_boundary_at_len() {
+        let s = SmallArcStr::<16>::from_static("héllo");
+        assert_eq!(&*s.get(0..s.len()).unwrap(), "héllo");
+    }
+
+    #[test]
+    fn arc_slice_make_mut_unique_full_range_reuses_allocation() {
+        let mut slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3]);
+        let ptr_before = slice.as_ptr();
+        slice.make_mut()[0] = 42;
+        assert_eq!(slice.as_ptr(), ptr_before);
+        assert_eq!(&*slice, [42, 2, 3]);
+    }
+
+    #[test]
+    fn arc_slice_make_mut_clones_when_shared() {
+        let mut slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3]);
+        let other = slice.clone();
+        let ptr_before = slice.as_ptr();
+        slice.make_mut()[0] = 42;
+        assert_ne!(slice.as_ptr(), ptr_before);
+        assert_eq!(&*other, [1, 2, 3]);
+        assert_eq!(&*slice, [42, 2, 3]);
+    }
+
+    #[test]
+    fn arc_slice_make_mut_partial_range_reallocates() {
+        let full: ArcSlice<i32> = ArcSlice::from([1, 2, 3, 4]);
+        let mut middle = full.slice(1..3);
+        drop(full);
+        middle.make_mut()[0] = 42;
+        assert_eq!(&*middle, [42, 3]);
+    }
+
+    #[test]
+    fn small_arc_slice_make_mut_inline_full_range_reuses_allocation() {
+        let mut slice: SmallArcSlice<i32, 4> = SmallArcSlice::from([1, 2, 3]);
+        let ptr_before = slice.as_ptr();
+        slice.make_mut()[0] = 42;
+        assert_eq!(slice.as_ptr(), ptr_before);
+        assert_eq!(&*slice, [42, 2, 3]);
+    }
+
+    #[test]
+    fn small_arc_slice_make_mut_shared_unique_full_range_reuses_allocation() {
+        let mut slice: SmallArcSlice<i32, 2> = SmallArcSlice::from([1, 2, 3]);
+        let ptr_before = slice.as_ptr();
+        slice.make_mut()[0] = 42;
+        assert_eq!(slice.as_ptr(), ptr_before);
+        assert_eq!(&*slice, [42, 2, 3]);
+    }
+
+    #[test]
+    fn small_arc_slice_make_mut_clones_when_shared() {
+        let mut slice: SmallArcSlice<i32, 2> = SmallArcSlice::from([1, 2, 3]);
+        let other = slice.clone();
+        let ptr_before = slice.as_ptr();
+        slice.make_mut()[0] = 42;
+        assert_ne!(slice.as_ptr(), ptr_before);
+        assert_eq!(&*other, [1, 2, 3]);
+        assert_eq!(&*slice, [42, 2, 3]);
+    }
+
+    #[test]
+    fn small_arc_slice_into_vec_inline_moves_without_cloning() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct CountClone(Rc<Cell<usize>>);
+
+        impl Clone for CountClone {
+            fn clone(&self) -> Self {
+                self.0.set(self.0.get() + 1);
+                CountClone(self.0.clone())
+            }
+        }
+
+        let counter = Rc::new(Cell::new(0));
+        let slice: SmallArcSlice<CountClone, 4> = SmallArcSlice::from([
+            CountClone(counter.clone()),
+            CountClone(counter.clone()),
+            CountClone(counter.clone()),
+        ]);
+        counter.set(0);
+        let values = slice.into_vec();
+        assert_eq!(values.len(), 3);
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range out of bounds")]
+    fn arc_slice_slice_panics_out_of_bounds() {
+        let slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3]);
+        let _ = slice.slice(0..10);
+    }
+
+    #[test]
+    #[should_panic(expected = "mid out of bounds")]
+    fn arc_slice_split_at_panics_out_of_bounds() {
+        let slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3]);
+        let _ = slice.split_at(10);
+    }
+
+    #[test]
+    fn arc_slice_get_returns_none_out_of_bounds_or_inverted() {
+        let slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3]);
+        assert!(slice.get(0..10).is_none());
+        let (start, end) = (2, 1);
+        assert!(slice.get(start..end).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "range out of bounds")]
+    fn small_arc_slice_slice_panics_out_of_bounds() {
+        let slice: SmallArcSlice<i32, 4> = SmallArcSlice::from([1, 2, 3]);
+        let _ = slice.slice(0..10);
+    }
+
+    #[test]
+    #[should_panic(expected = "mid out of bounds")]
+    fn small_arc_slice_split_at_panics_out_of_bounds() {
+        let slice: SmallArcSlice<i32, 4> = SmallArcSlice::from([1, 2, 3]);
+        let _ = slice.split_at(10);
+    }
+
+    #[test]
+    fn small_arc_slice_get_returns_none_out_of_bounds_or_inverted() {
+        let slice: SmallArcSlice<i32, 4> = SmallArcSlice::from([1, 2, 3]);
+        assert!(slice.get(0..10).is_none());
+        let (start, end) = (2, 1);
+        assert!(slice.get(start..end).is_none());
+    }
+
+    #[test]
+    fn arc_slice_static_variant_participates_in_clone_eq_and_iteration() {
+        static DATA: [i32; 3] = [1, 2, 3];
+        let slice = ArcSlice::from_static(&DATA);
+        let cloned = slice.clone();
+        assert_eq!(slice, cloned);
+        let collected: Vec<i32> = slice.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn small_arc_slice_static_variant_participates_in_clone_eq_and_iteration() {
+        static DATA: [i32; 3] = [1, 2, 3];
+        let slice: SmallArcSlice<i32, 4> = SmallArcSlice::from_static(&DATA);
+        let cloned = slice.clone();
+        assert_eq!(slice, cloned);
+        let collected: Vec<i32> = slice.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn arc_slice_iter_interleaves_next_and_next_back() {
+        let slice: ArcSlice<i32> = ArcSlice::from([1, 2, 3, 4, 5]);
+        let mut iter = slice.into_iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
 }